@@ -0,0 +1,47 @@
+use futures::stream::Fuse;
+use futures::{Async, Poll, Stream};
+
+pub struct BatchAvailable<S: Stream> {
+    stream: Fuse<S>,
+    error: Option<S::Error>,
+}
+
+impl<S: Stream> BatchAvailable<S> {
+    pub(crate) fn new(stream: S) -> Self {
+        BatchAvailable {
+            stream: stream.fuse(),
+            error: None,
+        }
+    }
+}
+
+impl<S: Stream> Stream for BatchAvailable<S> {
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        let first = match try_ready!(self.stream.poll()) {
+            Some(item) => item,
+            None => return Ok(Async::Ready(None)),
+        };
+
+        let mut batch = vec![first];
+
+        loop {
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(item))) => batch.push(item),
+                Ok(Async::Ready(None)) | Ok(Async::NotReady) => break,
+                Err(error) => {
+                    self.error = Some(error);
+                    break;
+                }
+            }
+        }
+
+        Ok(Async::Ready(Some(batch)))
+    }
+}
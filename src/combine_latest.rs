@@ -0,0 +1,83 @@
+use futures::stream::Fuse;
+use futures::{Async, Poll, Stream};
+
+pub struct CombineLatest<S: Stream, O: Stream> {
+    stream: Fuse<S>,
+    other: Fuse<O>,
+    latest: (Option<S::Item>, Option<O::Item>),
+    stream_done: bool,
+    other_done: bool,
+
+    // Set once either side has errored, so that neither is polled again
+    // afterwards (Fuse alone only stops polling after a clean end of stream,
+    // not after an error)
+    done: bool,
+}
+
+impl<S: Stream, O: Stream> CombineLatest<S, O> {
+    pub(crate) fn new(stream: S, other: O) -> Self {
+        CombineLatest {
+            stream: stream.fuse(),
+            other: other.fuse(),
+            latest: (None, None),
+            stream_done: false,
+            other_done: false,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream, O: Stream<Error = S::Error>> Stream for CombineLatest<S, O>
+where
+    S::Item: Clone,
+    O::Item: Clone,
+{
+    type Item = (S::Item, O::Item);
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(Async::Ready(None));
+        }
+
+        let mut changed = false;
+
+        if !self.stream_done {
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(item))) => {
+                    self.latest.0 = Some(item);
+                    changed = true;
+                }
+                Ok(Async::Ready(None)) => self.stream_done = true,
+                Ok(Async::NotReady) => {}
+                Err(err) => {
+                    self.done = true;
+                    return Err(err);
+                }
+            }
+        }
+
+        if !self.other_done {
+            match self.other.poll() {
+                Ok(Async::Ready(Some(item))) => {
+                    self.latest.1 = Some(item);
+                    changed = true;
+                }
+                Ok(Async::Ready(None)) => self.other_done = true,
+                Ok(Async::NotReady) => {}
+                Err(err) => {
+                    self.done = true;
+                    return Err(err);
+                }
+            }
+        }
+
+        match self.latest {
+            (Some(ref left), Some(ref right)) if changed => {
+                Ok(Async::Ready(Some((left.clone(), right.clone()))))
+            }
+            _ if self.stream_done && self.other_done => Ok(Async::Ready(None)),
+            _ => Ok(Async::NotReady),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use futures::stream::Fuse;
+use futures::{Async, Poll, Stream};
+
+pub struct FlattenUnordered<S: Stream>
+where
+    S::Item: Stream,
+{
+    stream: Fuse<S>,
+    limit: Option<usize>,
+    active: Vec<Fuse<S::Item>>,
+
+    // The index to resume scanning from on the next poll, so that a
+    // continuously-ready inner stream can't starve the ones after it
+    next_index: usize,
+
+    // Set once the outer stream or any active inner stream has errored, so
+    // that none of them are polled again afterwards (Fuse alone only stops
+    // polling after a clean end of stream, not after an error)
+    done: bool,
+}
+
+impl<S: Stream> FlattenUnordered<S>
+where
+    S::Item: Stream,
+{
+    pub(crate) fn new(stream: S, limit: impl Into<Option<usize>>) -> Self {
+        FlattenUnordered {
+            stream: stream.fuse(),
+            limit: limit.into(),
+            active: Vec::new(),
+            next_index: 0,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream> Stream for FlattenUnordered<S>
+where
+    S::Item: Stream<Error = <S as Stream>::Error>,
+{
+    type Item = <S::Item as Stream>::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(Async::Ready(None));
+        }
+
+        // Removing an exhausted inner stream frees up a concurrency slot which
+        // the outer stream might be able to fill synchronously, so loop until
+        // a round makes no further progress before giving up with NotReady
+        loop {
+            let mut stream_done = false;
+
+            // Pull in new inner streams until we hit the concurrency limit or
+            // the outer stream has nothing more to offer right now
+            while self.limit.map(|limit| self.active.len() < limit).unwrap_or(true) {
+                match self.stream.poll() {
+                    Ok(Async::Ready(Some(inner))) => self.active.push(inner.fuse()),
+                    Ok(Async::Ready(None)) => {
+                        stream_done = true;
+                        break;
+                    }
+                    Ok(Async::NotReady) => break,
+                    Err(err) => {
+                        self.done = true;
+                        return Err(err);
+                    }
+                }
+            }
+
+            // Round-robin poll each active inner stream starting from where the
+            // last emission left off, removing any which have completed, so
+            // that a continuously-ready stream can't starve the ones after it
+            let mut ready_item = None;
+            let mut removed_any = false;
+            let mut scanned = 0;
+            let mut index = self.next_index % self.active.len().max(1);
+
+            while scanned < self.active.len() {
+                match self.active[index].poll() {
+                    Ok(Async::Ready(Some(item))) => {
+                        ready_item = Some(item);
+                        self.next_index = index + 1;
+                        break;
+                    }
+                    Ok(Async::Ready(None)) => {
+                        let _ = self.active.remove(index);
+                        removed_any = true;
+                        if self.active.is_empty() {
+                            break;
+                        }
+                        index %= self.active.len();
+                    }
+                    Ok(Async::NotReady) => {
+                        index = (index + 1) % self.active.len();
+                        scanned += 1;
+                    }
+                    Err(err) => {
+                        self.done = true;
+                        return Err(err);
+                    }
+                }
+            }
+
+            if let Some(item) = ready_item {
+                return Ok(Async::Ready(Some(item)));
+            }
+
+            if stream_done && self.active.is_empty() {
+                return Ok(Async::Ready(None));
+            }
+
+            if !removed_any {
+                return Ok(Async::NotReady);
+            }
+        }
+    }
+}
@@ -14,12 +14,19 @@ where
     pending_items: Vec<Option<VecDeque<S::Item>>>,
 
     group_indices: HashMap<K, usize>,
-    pending_groups: Vec<Result<(K, Group<K, S, F>), S::Error>>,
+    pending_groups: Vec<(K, Group<K, S, F>)>,
+
+    // Once the source stream errors, the error is recorded here so it can be
+    // surfaced to whichever group is currently being polled rather than being
+    // lost or mistaken for a clean end of stream. This is `Arc`-wrapped so
+    // that it can be shared with every live group without requiring
+    // `S::Error: Clone` (e.g. `std::io::Error` isn't `Clone`).
+    error: Option<Arc<S::Error>>,
 }
 
 fn poll_next_group_item<K, S, F>(shared_state: &mut Arc<Mutex<GroupByState<K, S, F>>>,
                                  index: usize)
-                                 -> Poll<Option<S::Item>, ()>
+                                 -> Poll<Option<S::Item>, Arc<S::Error>>
     where S: Stream,
           K: Clone + Eq + Hash,
           F: FnMut(&S::Item) -> K
@@ -38,6 +45,12 @@ fn poll_next_group_item<K, S, F>(shared_state: &mut Arc<Mutex<GroupByState<K, S,
         }
     }
 
+    // If the source stream has already errored, surface that error to this
+    // group rather than re-polling a stream which may no longer be safe to poll
+    if let Some(ref err) = state.error {
+        return Err(err.clone());
+    }
+
     // Loop until we find an item for this group or the end of the stream
     loop {
         match state.stream.poll() {
@@ -78,17 +91,18 @@ fn poll_next_group_item<K, S, F>(shared_state: &mut Arc<Mutex<GroupByState<K, S,
                             state: shared_state.clone(),
                         };
 
-                        state.pending_groups.push(Ok((key, group)));
+                        state.pending_groups.push((key, group));
                         continue;
                     }
                 }
             }
             Err(err) => {
-                // If an error occurred, store it for the parent GroupBy to send
-                state.pending_groups.push(Err(err));
-
-                // After an error there are
-                return Ok(Async::Ready(None));
+                // Record the error so that it is observable from whichever group is
+                // currently being polled, rather than being indistinguishable from a
+                // clean end of stream
+                let err = Arc::new(err);
+                state.error = Some(err.clone());
+                return Err(err);
             }
             Ok(async_state) => return Ok(async_state),
         }
@@ -97,7 +111,7 @@ fn poll_next_group_item<K, S, F>(shared_state: &mut Arc<Mutex<GroupByState<K, S,
 }
 
 fn poll_next_group<K, S, F>(shared_state: &mut Arc<Mutex<GroupByState<K, S, F>>>)
-                            -> Poll<Option<(K, Group<K, S, F>)>, S::Error>
+                            -> Poll<Option<(K, Group<K, S, F>)>, Arc<S::Error>>
     where S: Stream,
           K: Clone + Eq + Hash,
           F: FnMut(&S::Item) -> K
@@ -105,13 +119,26 @@ fn poll_next_group<K, S, F>(shared_state: &mut Arc<Mutex<GroupByState<K, S, F>>>
     let mut state = shared_state.lock().unwrap();
 
     // Pop a pending group
-    if let Some(pending_group_result) = state.pending_groups.pop() {
-        return pending_group_result.map(|pending_group| Async::Ready(Some(pending_group)));
+    if let Some(pending_group) = state.pending_groups.pop() {
+        return Ok(Async::Ready(Some(pending_group)));
+    }
+
+    // If the source stream has already errored, surface that error here too
+    if let Some(ref err) = state.error {
+        return Err(err.clone());
     }
 
     // loop until we find the next group or the end of the stream
     loop {
-        let item = try_ready!(state.stream.poll());
+        let item = match state.stream.poll() {
+            Ok(Async::Ready(item)) => item,
+            Ok(Async::NotReady) => return Ok(Async::NotReady),
+            Err(err) => {
+                let err = Arc::new(err);
+                state.error = Some(err.clone());
+                return Err(err);
+            }
+        };
 
         match item {
             None => return Ok(Async::Ready(None)),
@@ -180,6 +207,7 @@ impl<K: Eq + Hash + Clone, S:Stream, F: FnMut(&S::Item) -> K> GroupBy<K, S, F> {
                 pending_items: Default::default(),
                 group_indices: Default::default(),
                 pending_groups: Default::default(),
+                error: None,
             })),
         }
     }
@@ -191,7 +219,7 @@ impl<K, S, F> Stream for Group<K, S, F>
           F: FnMut(&S::Item) -> K
 {
     type Item = S::Item;
-    type Error = ();
+    type Error = Arc<S::Error>;
 
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
         poll_next_group_item(&mut self.state, self.index)
@@ -204,7 +232,13 @@ impl<K, S, F> Stream for GroupBy<K, S, F>
           F: FnMut(&S::Item) -> K
 {
     type Item = (K, Group<K, S, F>);
-    type Error = S::Error;
+
+    // GroupBy's own Error is also widened to Arc<S::Error> here, not just
+    // Group's, since the two share the same recorded error (see
+    // GroupByState::error) and a single Arc is what lets it be handed to
+    // every live group without requiring S::Error: Clone. Existing callers
+    // matching on GroupBy's error type need to account for this.
+    type Error = Arc<S::Error>;
 
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
         poll_next_group(&mut self.state)
@@ -16,6 +16,18 @@ pub use enumerate::Enumerate;
 mod with_latest_from;
 pub use with_latest_from::WithLatestFrom;
 
+mod batch_available;
+pub use batch_available::BatchAvailable;
+
+mod combine_latest;
+pub use combine_latest::CombineLatest;
+
+mod sample;
+pub use sample::Sample;
+
+mod flatten_unordered;
+pub use flatten_unordered::FlattenUnordered;
+
 pub trait StreamTools: Stream {
     fn group_by<K, F>(self, f: F) -> GroupBy<K, Self, F>
     where
@@ -48,6 +60,40 @@ pub trait StreamTools: Stream {
     {
         WithLatestFrom::new(self, other)
     }
+
+    fn batch_available(self) -> BatchAvailable<Self>
+    where
+        Self: Sized,
+    {
+        BatchAvailable::new(self)
+    }
+
+    fn combine_latest<O>(self, other: O) -> CombineLatest<Self, O>
+    where
+        Self: Sized,
+        O: Stream<Error = Self::Error>,
+        Self::Item: Clone,
+        O::Item: Clone,
+    {
+        CombineLatest::new(self, other)
+    }
+
+    fn sample<T>(self, trigger: T) -> Sample<Self, T>
+    where
+        Self: Sized,
+        T: Stream<Error = Self::Error>,
+        Self::Item: Clone,
+    {
+        Sample::new(self, trigger)
+    }
+
+    fn flatten_unordered(self, limit: impl Into<Option<usize>>) -> FlattenUnordered<Self>
+    where
+        Self: Sized,
+        Self::Item: Stream<Error = Self::Error>,
+    {
+        FlattenUnordered::new(self, limit)
+    }
 }
 
 impl<S: Stream> StreamTools for S {}
@@ -55,7 +101,7 @@ impl<S: Stream> StreamTools for S {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use futures::{Future, Stream};
+    use futures::{Async, Future, Stream};
     use futures::stream;
     use futures::sync::mpsc;
     use std::time::Duration;
@@ -113,6 +159,47 @@ mod tests {
         assert_eq!(third_items, vec!["ABC"]);
     }
 
+    #[test]
+    fn group_by_propagates_source_errors_to_the_current_group() {
+        let results = vec![Ok("A"), Ok("AB"), Err(()), Ok("C")];
+        let mut group_by = stream::iter_result(results).group_by(|s| s.len());
+
+        let (_, mut first_group) = match group_by.poll().unwrap() {
+            Async::Ready(Some(group)) => group,
+            _ => panic!("expected the first group to be ready immediately"),
+        };
+
+        assert_eq!(first_group.poll().unwrap(), Async::Ready(Some("A")));
+
+        let (_, mut second_group) = match group_by.poll().unwrap() {
+            Async::Ready(Some(group)) => group,
+            _ => panic!("expected the second group to be ready immediately"),
+        };
+
+        // The source errors while looking for the next group, so GroupBy itself
+        // should surface it rather than treating it as the end of the stream
+        match group_by.poll() {
+            Err(err) => assert_eq!(*err, ()),
+            _ => panic!("expected the source error to propagate from GroupBy"),
+        }
+
+        // A group that was already being consumed should observe the same error
+        // instead of silently looking like it ended cleanly
+        match first_group.poll() {
+            Err(err) => assert_eq!(*err, ()),
+            _ => panic!("expected the source error to propagate from the first group"),
+        }
+
+        // "AB" was already buffered for this group before the error occurred, so
+        // it should still be delivered before the group observes the error
+        assert_eq!(second_group.poll().unwrap(), Async::Ready(Some("AB")));
+
+        match second_group.poll() {
+            Err(err) => assert_eq!(*err, ()),
+            _ => panic!("expected the source error to propagate from the second group"),
+        }
+    }
+
     #[test]
     fn latest_returns_latest() {
         let (tx, rx) = mpsc::unbounded();
@@ -173,8 +260,171 @@ mod tests {
 
         assert_eq!(items, vec![(1, "A"), (2, "C")]);
     }
-    
 
-    
+    #[test]
+    fn batch_available_coalesces_synchronously_ready_items() {
+        let (tx, rx) = mpsc::unbounded();
+
+        // Queue both items before the reader starts polling, so the first
+        // batch is guaranteed to coalesce them regardless of thread scheduling
+        tx.unbounded_send(0).unwrap();
+        tx.unbounded_send(1).unwrap();
+
+        let rx_thread = thread::spawn(move || {
+            rx.batch_available().collect().wait().unwrap()
+        });
+
+        let tx_thread = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            tx.unbounded_send(2).unwrap();
+        });
+
+        let batches = rx_thread.join().unwrap();
+        tx_thread.join().unwrap();
+
+        assert_eq!(batches, vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn combine_latest_emits_on_either_input() {
+        let (tx_main, rx_main) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+
+        let rx_thread = thread::spawn(move || {
+            rx_main
+                .combine_latest(rx)
+                .collect()
+                .wait()
+                .unwrap()
+        });
+
+        let tx_thread = thread::spawn(move || {
+            tx_main.unbounded_send(0).unwrap();
+
+            thread::sleep(Duration::from_millis(50));
+            tx.unbounded_send("A").unwrap();
+
+            thread::sleep(Duration::from_millis(50));
+            tx_main.unbounded_send(1).unwrap();
 
+            thread::sleep(Duration::from_millis(50));
+            tx.unbounded_send("B").unwrap();
+        });
+
+        let items = rx_thread.join().unwrap();
+        tx_thread.join().unwrap();
+
+        assert_eq!(items, vec![(0, "A"), (1, "A"), (1, "B")]);
+    }
+
+    #[test]
+    fn combine_latest_propagates_errors_and_then_stops() {
+        let left = stream::iter_result::<_, i32, ()>(vec![Err(())]);
+        let right = stream::iter_ok::<_, ()>(vec!["A"]);
+        let mut combined = left.combine_latest(right);
+
+        match combined.poll() {
+            Err(err) => assert_eq!(err, ()),
+            other => panic!("expected the error to propagate immediately, got {:?}", other),
+        }
+
+        // Once an error has been surfaced neither side should be polled again,
+        // so the still-pending "A" must never be observed
+        assert_eq!(combined.poll().unwrap(), Async::Ready(None));
+    }
+
+    #[test]
+    fn sample_emits_latest_source_value_on_trigger() {
+        let (tx_main, rx_main) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+
+        let rx_thread = thread::spawn(move || {
+            rx_main
+                .sample(rx)
+                .collect()
+                .wait()
+                .unwrap()
+        });
+
+        let tx_thread = thread::spawn(move || {
+            tx_main.unbounded_send(0).unwrap();
+
+            thread::sleep(Duration::from_millis(50));
+            tx.unbounded_send(()).unwrap();
+
+            thread::sleep(Duration::from_millis(50));
+            tx_main.unbounded_send(1).unwrap();
+            tx_main.unbounded_send(2).unwrap();
+
+            thread::sleep(Duration::from_millis(50));
+            tx.unbounded_send(()).unwrap();
+
+            // Give the consumer a chance to observe the trigger before the
+            // senders are dropped and close the streams
+            thread::sleep(Duration::from_millis(50));
+        });
+
+        let items = rx_thread.join().unwrap();
+        tx_thread.join().unwrap();
+
+        assert_eq!(items, vec![0, 2]);
+    }
+
+    #[test]
+    fn flatten_unordered_yields_all_items_from_every_inner_stream() {
+        let results = vec![
+            stream::iter_ok::<_, ()>(vec!["A", "B"]),
+            stream::iter_ok::<_, ()>(vec!["C"]),
+            stream::iter_ok::<_, ()>(vec!["D", "E"]),
+        ];
+        let stream = stream::iter_ok::<_, ()>(results);
+
+        let mut items: Vec<_> = stream
+            .flatten_unordered(2)
+            .collect()
+            .wait()
+            .expect("there should be no error flattening the streams");
+
+        items.sort();
+
+        assert_eq!(items, vec!["A", "B", "C", "D", "E"]);
+    }
+
+    #[test]
+    fn flatten_unordered_round_robins_so_a_busy_stream_cannot_starve_others() {
+        let inner: Vec<Box<dyn Stream<Item = &'static str, Error = ()>>> = vec![
+            Box::new(stream::repeat("A")),
+            Box::new(stream::iter_ok(vec!["B"])),
+        ];
+        let mut flattened = stream::iter_ok::<_, ()>(inner).flatten_unordered(2);
+
+        let mut saw_b = false;
+        for _ in 0..10 {
+            if let Async::Ready(Some(item)) = flattened.poll().unwrap() {
+                if item == "B" {
+                    saw_b = true;
+                    break;
+                }
+            }
+        }
+
+        assert!(saw_b, "the second inner stream should not be starved by the first");
+    }
+
+    #[test]
+    fn flatten_unordered_surfaces_the_first_error_and_then_stops() {
+        let inner = stream::iter_result(vec![Ok("A"), Err(()), Ok("C")]);
+        let mut flattened = stream::iter_ok::<_, ()>(vec![inner]).flatten_unordered(1);
+
+        assert_eq!(flattened.poll().unwrap(), Async::Ready(Some("A")));
+
+        match flattened.poll() {
+            Err(err) => assert_eq!(err, ()),
+            other => panic!("expected the inner stream's error to propagate, got {:?}", other),
+        }
+
+        // Once an error has been surfaced the inner stream must not be polled
+        // again, so "C" should never be observed
+        assert_eq!(flattened.poll().unwrap(), Async::Ready(None));
+    }
 }
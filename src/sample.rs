@@ -0,0 +1,63 @@
+use futures::stream::Fuse;
+use futures::{Async, Poll, Stream};
+
+pub struct Sample<S: Stream, T: Stream> {
+    stream: Fuse<S>,
+    trigger: Fuse<T>,
+    latest_value: Option<S::Item>,
+
+    // Set once either stream has errored, so that neither is polled again
+    // afterwards (Fuse alone only stops polling after a clean end of stream,
+    // not after an error)
+    done: bool,
+}
+
+impl<S: Stream, T: Stream> Sample<S, T> {
+    pub(crate) fn new(stream: S, trigger: T) -> Self {
+        Sample {
+            stream: stream.fuse(),
+            trigger: trigger.fuse(),
+            latest_value: None,
+            done: false,
+        }
+    }
+}
+
+impl<S: Stream, T: Stream<Error = S::Error>> Stream for Sample<S, T>
+where
+    S::Item: Clone,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(Async::Ready(None));
+        }
+
+        loop {
+            match self.stream.poll() {
+                Ok(Async::Ready(Some(value))) => self.latest_value = Some(value),
+                Ok(Async::Ready(None)) => return Ok(Async::Ready(None)),
+                Ok(Async::NotReady) => break,
+                Err(err) => {
+                    self.done = true;
+                    return Err(err);
+                }
+            }
+        }
+
+        match self.trigger.poll() {
+            Ok(Async::Ready(Some(_))) => match self.latest_value {
+                Some(ref value) => Ok(Async::Ready(Some(value.clone()))),
+                None => Ok(Async::NotReady),
+            },
+            Ok(Async::Ready(None)) => Ok(Async::Ready(None)),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(err) => {
+                self.done = true;
+                Err(err)
+            }
+        }
+    }
+}